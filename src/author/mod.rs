@@ -22,6 +22,9 @@ pub struct Author {
     pub links: Vec<Link>,
     pub thumb: Option<FileMetaId>,
     pub updated: DateTime<Utc>,
+    /// Defaults to the epoch for data serialized before `created` existed
+    #[serde(default)]
+    pub created: DateTime<Utc>,
 }
 
 impl Hash for Author {
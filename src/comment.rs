@@ -12,3 +12,37 @@ pub struct Comment {
     #[serde(skip_serializing_if = "<[_]>::is_empty")]
     pub replies: Vec<Comment>,
 }
+
+impl Comment {
+    /// Returns a copy with ASCII control characters (except `\n` and `\t`) removed  
+    /// from `user` and `text`, applied recursively to replies
+    pub fn sanitized(&self) -> Comment {
+        fn strip(s: &str) -> String {
+            s.chars()
+                .filter(|c| !c.is_ascii_control() || *c == '\n' || *c == '\t')
+                .collect()
+        }
+
+        Comment {
+            user: strip(&self.user),
+            text: strip(&self.text),
+            replies: self.replies.iter().map(Comment::sanitized).collect(),
+        }
+    }
+
+    /// Count this comment and all nested replies
+    pub fn count(&self) -> usize {
+        1 + self.replies.iter().map(Comment::count).sum::<usize>()
+    }
+
+    /// Iterate this comment and all nested replies depth-first  
+    /// yields `(depth, comment)`, starting at depth 0
+    pub fn iter_flat(&self) -> impl Iterator<Item = (usize, &Comment)> {
+        let mut stack = vec![(0, self)];
+        std::iter::from_fn(move || {
+            let (depth, comment) = stack.pop()?;
+            stack.extend(comment.replies.iter().rev().map(|reply| (depth + 1, reply)));
+            Some((depth, comment))
+        })
+    }
+}
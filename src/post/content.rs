@@ -11,4 +11,8 @@ use crate::id::FileMetaId;
 pub enum Content {
     Text(String),
     File(FileMetaId),
+    /// Stored as `{ "quote": "..." }` to stay distinct from `Text`
+    Quote {
+        quote: String,
+    },
 }
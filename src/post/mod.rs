@@ -4,6 +4,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "typescript")]
 use ts_rs::TS;
+use url::Url;
 
 pub mod content;
 pub mod tag;
@@ -29,6 +30,9 @@ pub struct Post {
     pub comments: Vec<Comment>,
     pub updated: DateTime<Utc>,
     pub published: DateTime<Utc>,
+    /// Defaults to the epoch for data serialized before `created` existed
+    #[serde(default)]
+    pub created: DateTime<Utc>,
 }
 
 impl Hash for Post {
@@ -51,3 +55,107 @@ impl PartialEq for Post {
     }
 }
 impl Eq for Post {}
+
+/// Fields that differ between two posts, see [`Post::diff`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PostDiff {
+    pub title: bool,
+    pub source: bool,
+    pub thumb: bool,
+    pub content: bool,
+    pub comments: bool,
+}
+
+impl PostDiff {
+    /// Returns true if nothing changed
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Post {
+    /// Returns the lowercased host of `source`  
+    /// `None` if there is no source or it is not a url with a host
+    pub fn source_host(&self) -> Option<String> {
+        let url = Url::parse(self.source.as_deref()?).ok()?;
+        url.host_str().map(str::to_lowercase)
+    }
+
+    /// Fingerprint of the content and comments, unlike [`Hash`] it ignores metadata  
+    /// FNV-1a 64 over a fixed byte encoding, stable across builds and platforms
+    pub fn content_fingerprint(&self) -> u64 {
+        let mut hasher = Fnv1a::default();
+        hasher.write_len(self.content.len());
+        for content in &self.content {
+            match content {
+                Content::Text(text) => {
+                    hasher.write(&[0]);
+                    hasher.write_str(text);
+                }
+                Content::File(id) => {
+                    hasher.write(&[1]);
+                    hasher.write(&id.raw().to_le_bytes());
+                }
+                Content::Quote { quote } => {
+                    hasher.write(&[2]);
+                    hasher.write_str(quote);
+                }
+            }
+        }
+        hasher.write_comments(&self.comments);
+        hasher.0
+    }
+
+    /// Count comments including nested replies
+    pub fn comment_count(&self) -> usize {
+        self.comments.iter().map(Comment::count).sum()
+    }
+
+    /// Compare with another post without touching the database  
+    /// content is compared by length, comments by [`Post::comment_count`]
+    pub fn diff(&self, other: &Post) -> PostDiff {
+        PostDiff {
+            title: self.title != other.title,
+            source: self.source != other.source,
+            thumb: self.thumb != other.thumb,
+            content: self.content.len() != other.content.len(),
+            comments: self.comment_count() != other.comment_count(),
+        }
+    }
+}
+
+/// FNV-1a 64, used by [`Post::content_fingerprint`]
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_len(&mut self, len: usize) {
+        self.write(&(len as u64).to_le_bytes());
+    }
+
+    fn write_str(&mut self, str: &str) {
+        self.write_len(str.len());
+        self.write(str.as_bytes());
+    }
+
+    fn write_comments(&mut self, comments: &[Comment]) {
+        self.write_len(comments.len());
+        for comment in comments {
+            self.write_str(&comment.user);
+            self.write_str(&comment.text);
+            self.write_comments(&comment.replies);
+        }
+    }
+}
@@ -1,11 +1,30 @@
-use crate::file_meta::FileMeta;
+use crate::{
+    file_meta::FileMeta,
+    id::{AuthorId, PostId},
+    post::{Content, Post},
+};
 
 #[cfg(feature = "utils")]
 mod utils;
 
+fn post() -> Post {
+    Post {
+        id: PostId::new(1),
+        author: AuthorId::new(1),
+        source: Some("https://example.com/1".to_string()),
+        title: "title".to_string(),
+        content: vec![Content::Text("text".to_string())],
+        thumb: None,
+        comments: vec![],
+        updated: Default::default(),
+        published: Default::default(),
+        created: Default::default(),
+    }
+}
+
 #[test]
 fn test_file_meta_path() {
-    use crate::id::{AuthorId, FileMetaId, PostId};
+    use crate::id::FileMetaId;
 
     let file_meta = FileMeta {
         id: FileMetaId::new(123),
@@ -19,3 +38,209 @@ fn test_file_meta_path() {
     let path = file_meta.path();
     assert_eq!(path.to_str().unwrap(), "456/789/test.jpg");
 }
+
+#[test]
+fn test_comment_sanitized() {
+    use crate::comment::Comment;
+
+    let comment = Comment {
+        user: "user\0".to_string(),
+        text: "hello\0 world\n\tbye\x07".to_string(),
+        replies: vec![Comment {
+            user: "reply".to_string(),
+            text: "\x1bnested\0".to_string(),
+            replies: vec![],
+        }],
+    };
+
+    let sanitized = comment.sanitized();
+    assert_eq!(sanitized.user, "user");
+    assert_eq!(sanitized.text, "hello world\n\tbye");
+    assert_eq!(sanitized.replies[0].text, "nested");
+}
+
+#[test]
+fn test_post_diff() {
+    use crate::{comment::Comment, id::FileMetaId, post::PostDiff};
+
+    let post = post();
+    assert!(post.diff(&post.clone()).is_empty());
+
+    let other = Post {
+        title: "new".to_string(),
+        ..post.clone()
+    };
+    assert_eq!(
+        post.diff(&other),
+        PostDiff {
+            title: true,
+            ..Default::default()
+        }
+    );
+
+    let other = Post {
+        source: None,
+        ..post.clone()
+    };
+    assert_eq!(
+        post.diff(&other),
+        PostDiff {
+            source: true,
+            ..Default::default()
+        }
+    );
+
+    let other = Post {
+        thumb: Some(FileMetaId::new(1)),
+        ..post.clone()
+    };
+    assert_eq!(
+        post.diff(&other),
+        PostDiff {
+            thumb: true,
+            ..Default::default()
+        }
+    );
+
+    let other = Post {
+        content: vec![],
+        ..post.clone()
+    };
+    assert_eq!(
+        post.diff(&other),
+        PostDiff {
+            content: true,
+            ..Default::default()
+        }
+    );
+
+    // a new reply changes the count even if top-level comments stay the same
+    let comment = |replies| Comment {
+        user: "user".to_string(),
+        text: "text".to_string(),
+        replies,
+    };
+    let post = Post {
+        comments: vec![comment(vec![])],
+        ..post
+    };
+    let other = Post {
+        comments: vec![comment(vec![comment(vec![])])],
+        ..post.clone()
+    };
+    assert_eq!(
+        post.diff(&other),
+        PostDiff {
+            comments: true,
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn test_post_content_fingerprint() {
+    use crate::{comment::Comment, id::FileMetaId};
+    use chrono::Utc;
+
+    let post = Post {
+        content: vec![
+            Content::Text("text".to_string()),
+            Content::File(FileMetaId::new(1)),
+        ],
+        ..post()
+    };
+
+    let updated = Post {
+        updated: Utc::now(),
+        ..post.clone()
+    };
+    assert_eq!(post.content_fingerprint(), updated.content_fingerprint());
+
+    let changed = Post {
+        content: vec![Content::Text("other".to_string())],
+        ..post.clone()
+    };
+    assert_ne!(post.content_fingerprint(), changed.content_fingerprint());
+
+    let commented = Post {
+        comments: vec![Comment {
+            user: "user".to_string(),
+            text: "text".to_string(),
+            replies: vec![],
+        }],
+        ..post.clone()
+    };
+    assert_ne!(post.content_fingerprint(), commented.content_fingerprint());
+
+    // stored across runs, so the value itself must not change
+    assert_eq!(post.content_fingerprint(), 15582900683873488264);
+}
+
+#[test]
+fn test_content_quote_deserialize() {
+    use crate::post::Content;
+    use serde::{
+        de::value::{Error, MapDeserializer, StrDeserializer},
+        Deserialize,
+    };
+
+    let text = Content::deserialize(StrDeserializer::<Error>::new("text")).unwrap();
+    assert!(matches!(text, Content::Text(text) if text == "text"));
+
+    let quote = Content::deserialize(MapDeserializer::<_, Error>::new(
+        [("quote", "quoted")].into_iter(),
+    ))
+    .unwrap();
+    assert!(matches!(quote, Content::Quote { quote } if quote == "quoted"));
+}
+
+#[test]
+fn test_post_source_host() {
+    let post = |source: Option<&str>| Post {
+        source: source.map(str::to_string),
+        ..post()
+    };
+
+    assert_eq!(
+        post(Some("https://User@Www.Fanbox.cc:443/posts/1?a=b")).source_host(),
+        Some("www.fanbox.cc".to_string())
+    );
+    assert_eq!(
+        post(Some("http://[::1]:8080/p")).source_host(),
+        Some("[::1]".to_string())
+    );
+    assert_eq!(post(None).source_host(), None);
+    assert_eq!(post(Some("not a url")).source_host(), None);
+}
+
+#[test]
+fn test_comment_count_and_iter_flat() {
+    use crate::comment::Comment;
+
+    let comment = |text: &str, replies: Vec<Comment>| Comment {
+        user: "user".to_string(),
+        text: text.to_string(),
+        replies,
+    };
+
+    let root = comment(
+        "a",
+        vec![
+            comment("b", vec![comment("c", vec![])]),
+            comment("d", vec![]),
+        ],
+    );
+
+    assert_eq!(root.count(), 4);
+    let flat: Vec<_> = root
+        .iter_flat()
+        .map(|(depth, comment)| (depth, comment.text.as_str()))
+        .collect();
+    assert_eq!(flat, vec![(0, "a"), (1, "b"), (2, "c"), (1, "d")]);
+
+    let post = Post {
+        comments: vec![root, comment("e", vec![])],
+        ..post()
+    };
+    assert_eq!(post.comment_count(), 5);
+}
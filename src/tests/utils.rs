@@ -1,9 +1,27 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use rusqlite::Connection;
+
 use crate::{
     file_meta::FileMeta,
     id::{AuthorId, FileMetaId, PostId},
-    utils::get_mime,
+    utils::{get_mime, TEMPLATE_DATABASE_UP_SQL, UPGRADE_DATABASE_SQL},
 };
 
+/// In-memory database from the template with a single author (id 1)
+fn open_template() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch(TEMPLATE_DATABASE_UP_SQL).unwrap();
+    conn.execute("INSERT INTO authors (name) VALUES ('octocat')", [])
+        .unwrap();
+    conn
+}
+
+fn parse_datetime(datetime: &str) -> DateTime<Utc> {
+    NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M:%S")
+        .unwrap()
+        .and_utc()
+}
+
 #[test]
 fn test_guess_mime() {
     let file_meta = FileMeta {
@@ -17,3 +35,92 @@ fn test_guess_mime() {
     let mime = get_mime(&file_meta.filename);
     assert_eq!(mime, "image/jpeg");
 }
+
+#[test]
+fn test_author_created() {
+    let conn = open_template();
+
+    let created: String = conn
+        .query_row("SELECT created FROM authors", [], |row| row.get(0))
+        .unwrap();
+    assert!((Utc::now() - parse_datetime(&created)).num_minutes().abs() < 1);
+}
+
+#[test]
+fn test_post_created() {
+    let conn = open_template();
+    conn.execute(
+        "INSERT INTO posts (author, title, content, published) VALUES (1, 'old', '[]', '2001-01-01 00:00:00')",
+        [],
+    )
+    .unwrap();
+
+    let (published, created): (String, String) = conn
+        .query_row("SELECT published, created FROM posts", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!(published, "2001-01-01 00:00:00");
+    assert!((Utc::now() - parse_datetime(&created)).num_minutes().abs() < 1);
+}
+
+#[test]
+fn test_upgrade_author_created() {
+    let conn = open_template();
+    conn.execute_batch(
+        "ALTER TABLE authors DROP COLUMN created;
+        UPDATE authors SET updated = '2001-01-01 00:00:00';
+        ALTER TABLE posts DROP COLUMN created;",
+    )
+    .unwrap();
+
+    conn.execute_batch(UPGRADE_DATABASE_SQL).unwrap();
+
+    let created: String = conn
+        .query_row("SELECT created FROM authors WHERE id = 1", [], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(created, "2001-01-01 00:00:00");
+
+    conn.execute("INSERT INTO authors (name) VALUES ('new')", [])
+        .unwrap();
+    let created: String = conn
+        .query_row("SELECT created FROM authors WHERE id = 2", [], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert!((Utc::now() - parse_datetime(&created)).num_minutes().abs() < 1);
+}
+
+#[test]
+fn test_upgrade_post_created() {
+    let conn = open_template();
+    conn.execute_batch(
+        "ALTER TABLE authors DROP COLUMN created;
+        ALTER TABLE posts DROP COLUMN created;
+        INSERT INTO posts (author, title, content, published) VALUES (1, 'old', '[]', '2001-01-01 00:00:00');",
+    )
+    .unwrap();
+
+    conn.execute_batch(UPGRADE_DATABASE_SQL).unwrap();
+
+    let created: String = conn
+        .query_row("SELECT created FROM posts WHERE id = 1", [], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(created, "2001-01-01 00:00:00");
+
+    conn.execute(
+        "INSERT INTO posts (author, title, content, published) VALUES (1, 'new', '[]', '2001-01-01 00:00:00')",
+        [],
+    )
+    .unwrap();
+    let created: String = conn
+        .query_row("SELECT created FROM posts WHERE id = 2", [], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert!((Utc::now() - parse_datetime(&created)).num_minutes().abs() < 1);
+}
@@ -7,7 +7,8 @@ CREATE TABLE
         name TEXT NOT NULL COLLATE NOCASE,
         links JSON NOT NULL DEFAULT '[]',
         thumb INTEGER,
-        updated DATETIME NOT NULL DEFAULT "1970-01-01 00:00:00"
+        updated DATETIME NOT NULL DEFAULT "1970-01-01 00:00:00",
+        created DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
     );
 
 -- Alias ---------------------------------------------------
@@ -33,6 +34,7 @@ CREATE TABLE
         comments JSON NOT NULL DEFAULT '[]',
         updated DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
         published DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        created DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
         FOREIGN KEY (author) REFERENCES authors (id) ON DELETE CASCADE
     );
 
@@ -0,0 +1,40 @@
+-- Run once on archives created from an older `template.up.sql`
+------------------------------------------------------------
+-- Author created
+------------------------------------------------------------
+-- ALTER TABLE cannot add a CURRENT_TIMESTAMP default,
+-- backfill from `updated` and stamp new rows with a trigger instead
+ALTER TABLE authors
+ADD COLUMN created DATETIME NOT NULL DEFAULT "1970-01-01 00:00:00";
+
+UPDATE authors
+SET
+    created = updated;
+
+CREATE TRIGGER set_author_created_on_insert AFTER INSERT ON authors WHEN NEW.created = "1970-01-01 00:00:00" BEGIN
+UPDATE authors
+SET
+    created = CURRENT_TIMESTAMP
+WHERE
+    id = NEW.id;
+
+END;
+
+------------------------------------------------------------
+-- Post created
+------------------------------------------------------------
+ALTER TABLE posts
+ADD COLUMN created DATETIME NOT NULL DEFAULT "1970-01-01 00:00:00";
+
+UPDATE posts
+SET
+    created = published;
+
+CREATE TRIGGER set_post_created_on_insert AFTER INSERT ON posts WHEN NEW.created = "1970-01-01 00:00:00" BEGIN
+UPDATE posts
+SET
+    created = CURRENT_TIMESTAMP
+WHERE
+    id = NEW.id;
+
+END;